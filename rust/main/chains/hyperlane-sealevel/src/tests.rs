@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::parse_accounts::ParsedAccountSource;
+use solana_transaction_status::{
+    ParsedAccount, UiCompiledInstruction, UiInnerInstructions, UiInstruction, UiLoadedAddresses,
+    UiMessage, UiParsedInstruction, UiParsedMessage, UiPartiallyDecodedInstruction, UiTransaction,
+    UiTransactionStatusMeta,
+};
+
+use super::*;
+
+fn meta_with(
+    loaded_addresses: OptionSerializer<UiLoadedAddresses>,
+    inner_instructions: OptionSerializer<Vec<UiInnerInstructions>>,
+) -> UiTransactionStatusMeta {
+    UiTransactionStatusMeta {
+        loaded_addresses,
+        inner_instructions,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn combined_account_keys_without_address_lookup_table_returns_static_keys_only() {
+    let static_keys = vec!["static-0".to_string(), "static-1".to_string()];
+
+    let account_keys = combined_account_keys(static_keys.clone(), &OptionSerializer::None);
+
+    assert_eq!(account_keys, static_keys);
+}
+
+#[test]
+fn combined_account_keys_orders_writable_before_readonly_loaded_addresses() {
+    let static_keys = vec!["static-0".to_string(), "static-1".to_string()];
+    let loaded_addresses = UiLoadedAddresses {
+        writable: vec!["writable-0".to_string()],
+        readonly: vec!["readonly-0".to_string(), "readonly-1".to_string()],
+    };
+
+    let account_keys =
+        combined_account_keys(static_keys, &OptionSerializer::Some(loaded_addresses));
+
+    assert_eq!(
+        account_keys,
+        vec![
+            "static-0".to_string(),
+            "static-1".to_string(),
+            "writable-0".to_string(),
+            "readonly-0".to_string(),
+            "readonly-1".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn combined_account_keys_treats_none_and_skip_as_no_loaded_addresses() {
+    let static_keys = vec!["static-0".to_string()];
+
+    assert_eq!(
+        combined_account_keys(static_keys.clone(), &OptionSerializer::None),
+        static_keys
+    );
+    assert_eq!(
+        combined_account_keys(static_keys.clone(), &OptionSerializer::Skip),
+        static_keys
+    );
+}
+
+#[test]
+fn account_index_map_maps_each_key_to_its_position() {
+    let account_keys = vec![
+        "static-0".to_string(),
+        "static-1".to_string(),
+        "writable-0".to_string(),
+    ];
+
+    let map = account_index_map(&account_keys);
+
+    assert_eq!(map.get("static-0"), Some(&0));
+    assert_eq!(map.get("static-1"), Some(&1));
+    assert_eq!(map.get("writable-0"), Some(&2));
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn instructions_resolves_parsed_partially_decoded_inner_instruction() {
+    let account_index_map: HashMap<String, usize> = [
+        ("mailbox".to_string(), 0),
+        ("payer".to_string(), 1),
+        ("alt-loaded-pda".to_string(), 2),
+    ]
+    .into_iter()
+    .collect();
+
+    let inner_instructions = OptionSerializer::Some(vec![UiInnerInstructions {
+        index: 0,
+        instructions: vec![UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(
+            UiPartiallyDecodedInstruction {
+                program_id: "mailbox".to_string(),
+                accounts: vec!["payer".to_string(), "alt-loaded-pda".to_string()],
+                data: "deadbeef".to_string(),
+                stack_height: Some(2),
+            },
+        ))],
+    }]);
+    let meta = meta_with(OptionSerializer::None, inner_instructions);
+
+    let resolved = instructions(vec![], meta, &account_index_map);
+
+    assert_eq!(
+        resolved,
+        vec![UiCompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![1, 2],
+            data: "deadbeef".to_string(),
+            stack_height: Some(2),
+        }]
+    );
+}
+
+#[test]
+fn instructions_drops_partially_decoded_instruction_with_unresolvable_account() {
+    let account_index_map: HashMap<String, usize> =
+        [("mailbox".to_string(), 0)].into_iter().collect();
+
+    let inner_instructions = OptionSerializer::Some(vec![UiInnerInstructions {
+        index: 0,
+        instructions: vec![UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(
+            UiPartiallyDecodedInstruction {
+                program_id: "mailbox".to_string(),
+                accounts: vec!["not-in-account-keys".to_string()],
+                data: "deadbeef".to_string(),
+                stack_height: None,
+            },
+        ))],
+    }]);
+    let meta = meta_with(OptionSerializer::None, inner_instructions);
+
+    let resolved = instructions(vec![], meta, &account_index_map);
+
+    assert!(resolved.is_empty());
+}
+
+#[test]
+fn filter_by_validity_resolves_parsed_top_level_message_with_alt_loaded_cpi_account() {
+    // Real `jsonParsed` responses already fold ALT-loaded addresses into
+    // `UiParsedMessage.account_keys` (tagged `source: LookupTable`, appended writable-then-readonly)
+    // alongside `meta.loaded_addresses` describing the same addresses, so the parsed path does not
+    // pass them through `combined_account_keys` a second time. The Mailbox CPI call arrives as a `PartiallyDecoded`
+    // instruction nested under `meta.inner_instructions`, referencing the ALT-loaded PDA.
+    let tx = UiTransaction {
+        signatures: vec!["1".repeat(64)],
+        message: UiMessage::Parsed(UiParsedMessage {
+            account_keys: vec![
+                ParsedAccount {
+                    pubkey: "payer".to_string(),
+                    writable: true,
+                    signer: true,
+                    source: None,
+                },
+                ParsedAccount {
+                    pubkey: "mailbox".to_string(),
+                    writable: false,
+                    signer: false,
+                    source: None,
+                },
+                ParsedAccount {
+                    pubkey: "alt-loaded-pda".to_string(),
+                    writable: true,
+                    signer: false,
+                    source: Some(ParsedAccountSource::LookupTable),
+                },
+            ],
+            recent_blockhash: "11111111111111111111111111111111".to_string(),
+            instructions: vec![],
+            address_table_lookups: None,
+        }),
+    };
+
+    let loaded_addresses = OptionSerializer::Some(UiLoadedAddresses {
+        writable: vec!["alt-loaded-pda".to_string()],
+        readonly: vec![],
+    });
+    let inner_instructions = OptionSerializer::Some(vec![UiInnerInstructions {
+        index: 0,
+        instructions: vec![UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(
+            UiPartiallyDecodedInstruction {
+                program_id: "mailbox".to_string(),
+                accounts: vec!["alt-loaded-pda".to_string()],
+                data: "deadbeef".to_string(),
+                stack_height: Some(2),
+            },
+        ))],
+    }]);
+    let meta = meta_with(loaded_addresses, inner_instructions);
+
+    let (_, account_index_map, instructions) = filter_by_validity(tx, meta)
+        .expect("a Parsed top-level message must not be rejected as invalid");
+
+    // The ALT-loaded PDA appears once, at the index from `UiParsedMessage.account_keys`, not
+    // re-appended past it from `meta.loaded_addresses`.
+    assert_eq!(account_index_map.len(), 3);
+    assert_eq!(account_index_map.get("alt-loaded-pda"), Some(&2));
+    assert_eq!(
+        instructions,
+        vec![UiCompiledInstruction {
+            program_id_index: *account_index_map.get("mailbox").unwrap() as u8,
+            accounts: vec![2],
+            data: "deadbeef".to_string(),
+            stack_height: Some(2),
+        }]
+    );
+}
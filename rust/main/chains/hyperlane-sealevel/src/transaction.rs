@@ -5,7 +5,8 @@ use solana_sdk::pubkey::Pubkey;
 use solana_transaction_status::option_serializer::OptionSerializer;
 use solana_transaction_status::{
     EncodedTransaction, EncodedTransactionWithStatusMeta, UiCompiledInstruction, UiInstruction,
-    UiMessage, UiTransaction, UiTransactionStatusMeta,
+    UiLoadedAddresses, UiMessage, UiParsedInstruction, UiPartiallyDecodedInstruction,
+    UiTransaction, UiTransactionStatusMeta,
 };
 use tracing::warn;
 
@@ -47,15 +48,16 @@ where
         .enumerate()
         .filter_map(|(index, tx)| filter_by_encoding(tx).map(|(tx, meta)| (index, tx, meta)))
         .filter_map(|(index, tx, meta)| {
-            filter_by_validity(tx, meta)
-                .map(|(hash, account_keys, instructions)| (index, hash, account_keys, instructions))
+            filter_by_validity(tx, meta).map(|(hash, account_index_map, instructions)| {
+                (index, hash, account_index_map, instructions)
+            })
         })
-        .filter_map(|(index, hash, account_keys, instructions)| {
+        .filter_map(|(index, hash, account_index_map, instructions)| {
             filter_by_relevancy(
                 mailbox_program_id,
                 message_storage_pda_pubkey,
                 hash,
-                account_keys,
+                account_index_map,
                 instructions,
                 is_specified_message_instruction,
             )
@@ -68,15 +70,13 @@ fn filter_by_relevancy<F>(
     mailbox_program_id: &Pubkey,
     message_storage_pda_pubkey: &Pubkey,
     hash: H512,
-    account_keys: Vec<String>,
+    account_index_map: HashMap<String, usize>,
     instructions: Vec<UiCompiledInstruction>,
     is_specified_message_instruction: &F,
 ) -> Option<H512>
 where
     F: Fn(Instruction) -> bool,
 {
-    let account_index_map = account_index_map(account_keys);
-
     let mailbox_program_id_str = mailbox_program_id.to_string();
     let mailbox_program_index = match account_index_map.get(&mailbox_program_id_str) {
         Some(i) => *i as u8,
@@ -136,7 +136,7 @@ pub fn is_message_delivery_instruction(instruction: Instruction) -> bool {
 fn filter_by_validity(
     tx: UiTransaction,
     meta: UiTransactionStatusMeta,
-) -> Option<(H512, Vec<String>, Vec<UiCompiledInstruction>)> {
+) -> Option<(H512, HashMap<String, usize>, Vec<UiCompiledInstruction>)> {
     let Some(transaction_hash) = tx
         .signatures
         .first()
@@ -150,14 +150,38 @@ fn filter_by_validity(
         return None;
     };
 
-    let UiMessage::Raw(message) = tx.message else {
-        warn!(message = ?tx.message, "we expect messages in Raw format");
-        return None;
-    };
+    let (account_keys, top_level_instructions) =
+        message_account_keys_and_instructions(tx.message, &meta.loaded_addresses);
+
+    let account_index_map = account_index_map(&account_keys);
+    let instructions = instructions(top_level_instructions, meta, &account_index_map);
 
-    let instructions = instructions(message.instructions, meta);
+    Some((transaction_hash, account_index_map, instructions))
+}
 
-    Some((transaction_hash, message.account_keys, instructions))
+/// Extracts the effective account keys and top-level instructions, handling raw and `jsonParsed` messages alike.
+fn message_account_keys_and_instructions(
+    message: UiMessage,
+    loaded_addresses: &OptionSerializer<UiLoadedAddresses>,
+) -> (Vec<String>, Vec<UiInstruction>) {
+    match message {
+        UiMessage::Raw(message) => (
+            combined_account_keys(message.account_keys, loaded_addresses),
+            message
+                .instructions
+                .into_iter()
+                .map(UiInstruction::Compiled)
+                .collect(),
+        ),
+        UiMessage::Parsed(message) => (
+            message
+                .account_keys
+                .into_iter()
+                .map(|account| account.pubkey)
+                .collect(),
+            message.instructions,
+        ),
+    }
 }
 
 fn filter_by_encoding(
@@ -177,33 +201,83 @@ fn filter_by_encoding(
     }
 }
 
-fn account_index_map(account_keys: Vec<String>) -> HashMap<String, usize> {
+/// Appends ALT loaded addresses (writable, then readonly) to the static account keys, matching the Solana runtime's account-index ordering.
+fn combined_account_keys(
+    static_account_keys: Vec<String>,
+    loaded_addresses: &OptionSerializer<UiLoadedAddresses>,
+) -> Vec<String> {
+    let mut account_keys = static_account_keys;
+    if let OptionSerializer::Some(loaded_addresses) = loaded_addresses {
+        account_keys.extend(loaded_addresses.writable.iter().cloned());
+        account_keys.extend(loaded_addresses.readonly.iter().cloned());
+    }
     account_keys
-        .into_iter()
+}
+
+fn account_index_map(account_keys: &[String]) -> HashMap<String, usize> {
+    account_keys
+        .iter()
         .enumerate()
-        .map(|(index, key)| (key, index))
+        .map(|(index, key)| (key.clone(), index))
         .collect::<HashMap<String, usize>>()
 }
 
-/// Extract all instructions from transaction
+/// Extract all instructions from transaction, both top-level and inner
 fn instructions(
-    instruction: Vec<UiCompiledInstruction>,
+    instructions: Vec<UiInstruction>,
     meta: UiTransactionStatusMeta,
+    account_index_map: &HashMap<String, usize>,
 ) -> Vec<UiCompiledInstruction> {
+    let top_level_instructions = instructions
+        .into_iter()
+        .filter_map(|ii| resolve_instruction(ii, account_index_map))
+        .collect::<Vec<UiCompiledInstruction>>();
+
     let inner_instructions = match meta.inner_instructions {
         OptionSerializer::Some(ii) => ii
             .into_iter()
             .flat_map(|ii| ii.instructions)
-            .flat_map(|ii| match ii {
-                UiInstruction::Compiled(ci) => Some(ci),
-                _ => None,
-            })
+            .filter_map(|ii| resolve_instruction(ii, account_index_map))
             .collect::<Vec<UiCompiledInstruction>>(),
         OptionSerializer::None | OptionSerializer::Skip => vec![],
     };
 
-    [instruction, inner_instructions].concat()
+    [top_level_instructions, inner_instructions].concat()
+}
+
+/// Resolves a `UiInstruction` to a `UiCompiledInstruction`, dropping variants we don't recognize.
+fn resolve_instruction(
+    instruction: UiInstruction,
+    account_index_map: &HashMap<String, usize>,
+) -> Option<UiCompiledInstruction> {
+    match instruction {
+        UiInstruction::Compiled(ci) => Some(ci),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(pdi)) => {
+            partially_decoded_instruction(pdi, account_index_map)
+        }
+        _ => None,
+    }
+}
+
+/// Reconstructs a `UiCompiledInstruction` by resolving `program_id`/`accounts` pubkeys to indices via `account_index_map`
+fn partially_decoded_instruction(
+    instruction: UiPartiallyDecodedInstruction,
+    account_index_map: &HashMap<String, usize>,
+) -> Option<UiCompiledInstruction> {
+    let program_id_index = *account_index_map.get(&instruction.program_id)? as u8;
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|account| account_index_map.get(account).map(|i| *i as u8))
+        .collect::<Option<Vec<u8>>>()?;
+
+    Some(UiCompiledInstruction {
+        program_id_index,
+        accounts,
+        data: instruction.data,
+        stack_height: instruction.stack_height,
+    })
 }
 
 #[cfg(test)]
-mod tests;
\ No newline at end of file
+mod tests;